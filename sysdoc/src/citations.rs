@@ -0,0 +1,497 @@
+//! BibTeX bibliography parsing and inline citation rendering
+//!
+//! A document can declare a `.bib` bibliography via
+//! [`DocumentMetadata::bibliography`](crate::unified_document::DocumentMetadata::bibliography).
+//! Inline citation markers like `[@smith2020]` (or `[@a; @b]` for multiple
+//! keys) are recognized while walking events in [`Section::parse_content`](crate::model::Section::parse_content)
+//! and replaced with numbered or author-year links. A final "References"
+//! section can then be synthesized from only the entries actually cited.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use crate::source_model::{MarkdownBlock, MarkdownSection, SectionNumber};
+
+/// A single BibTeX entry, keyed by its citation key (e.g. "smith2020")
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BibEntry {
+    pub key: String,
+    pub entry_type: String,
+    pub author: Option<String>,
+    pub year: Option<String>,
+    pub title: Option<String>,
+}
+
+/// How inline citations and the References section are formatted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CitationStyle {
+    /// `[1]`, `[2]`, ... numbered in first-citation order
+    Numeric,
+    /// `(Smith, 2020)`
+    AuthorYear,
+}
+
+/// A parsed `.bib` file, keyed by citation key
+#[derive(Debug, Clone, Default)]
+pub struct Bibliography {
+    entries: BTreeMap<String, BibEntry>,
+}
+
+impl Bibliography {
+    /// Parse a `.bib` file's contents into a key -> entry map
+    ///
+    /// # Parameters
+    /// * `content` - Raw BibTeX source
+    ///
+    /// # Returns
+    /// * `Bibliography` - Entries keyed by citation key; malformed entries are skipped
+    pub fn parse(content: &str) -> Self {
+        let mut entries = BTreeMap::new();
+        for entry in parse_bib_entries(content) {
+            entries.insert(entry.key.clone(), entry);
+        }
+        Self { entries }
+    }
+
+    /// Load and parse a `.bib` file from disk
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(Self::parse(&content))
+    }
+
+    /// Look up an entry by citation key
+    pub fn get(&self, key: &str) -> Option<&BibEntry> {
+        self.entries.get(key)
+    }
+
+    /// Whether no entries were parsed
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Minimal BibTeX entry parser: handles `@type{key, field = {value}, ...}`
+/// entries, extracting `author`/`year`/`title`. Unrecognized fields are
+/// ignored and malformed entries are skipped rather than erroring, since a
+/// single bad entry shouldn't block the rest of the bibliography.
+fn parse_bib_entries(content: &str) -> Vec<BibEntry> {
+    let mut entries = Vec::new();
+    let mut rest = content;
+
+    while let Some(at_pos) = rest.find('@') {
+        rest = &rest[at_pos + 1..];
+        let Some(brace_pos) = rest.find('{') else {
+            break;
+        };
+        let entry_type = rest[..brace_pos].trim().to_lowercase();
+        rest = &rest[brace_pos + 1..];
+
+        let Some(end_pos) = find_matching_brace(rest) else {
+            break;
+        };
+        let body = &rest[..end_pos];
+        rest = &rest[end_pos + 1..];
+
+        let Some(comma_pos) = body.find(',') else {
+            continue;
+        };
+        let key = body[..comma_pos].trim().to_string();
+        if key.is_empty() {
+            continue;
+        }
+        let fields = parse_bib_fields(&body[comma_pos + 1..]);
+
+        entries.push(BibEntry {
+            key,
+            entry_type,
+            author: fields.get("author").cloned(),
+            year: fields.get("year").cloned(),
+            title: fields.get("title").cloned(),
+        });
+    }
+
+    entries
+}
+
+/// Find the index of the `}` that closes the `{` already consumed before `s`
+fn find_matching_brace(s: &str) -> Option<usize> {
+    let mut depth = 1;
+    for (i, c) in s.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_bib_fields(body: &str) -> BTreeMap<String, String> {
+    let mut fields = BTreeMap::new();
+    for part in split_top_level(body, ',') {
+        let Some(eq_pos) = part.find('=') else {
+            continue;
+        };
+        let name = part[..eq_pos].trim().to_lowercase();
+        let value = part[eq_pos + 1..]
+            .trim()
+            .trim_matches(|c| c == '{' || c == '}' || c == '"');
+        if !name.is_empty() {
+            fields.insert(name, value.to_string());
+        }
+    }
+    fields
+}
+
+/// Split on a top-level separator, ignoring separators nested inside `{}`
+fn split_top_level(s: &str, sep: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            c if c == sep && depth == 0 => {
+                parts.push(s[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    let tail = s[start..].trim();
+    if !tail.is_empty() {
+        parts.push(tail);
+    }
+    parts
+}
+
+/// A diagnostic raised when an inline citation references an unknown key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CitationDiagnostic {
+    pub key: String,
+    pub source_file: PathBuf,
+}
+
+impl fmt::Display for CitationDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unknown citation key \"{}\" in {}",
+            self.key,
+            self.source_file.display()
+        )
+    }
+}
+
+/// Tracks first-citation order across a document so numeric style numbers
+/// citations in the order they're first encountered, not bibliography order.
+#[derive(Debug, Clone, Default)]
+pub struct CitationOrder {
+    order: Vec<String>,
+}
+
+impl CitationOrder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get (assigning if new) the 1-based citation number for `key`
+    fn number_for(&mut self, key: &str) -> usize {
+        match self.order.iter().position(|k| k == key) {
+            Some(pos) => pos + 1,
+            None => {
+                self.order.push(key.to_string());
+                self.order.len()
+            }
+        }
+    }
+
+    /// Keys cited so far, in first-citation order
+    pub fn cited_keys(&self) -> &[String] {
+        &self.order
+    }
+}
+
+/// Find all `[@key]` / `[@a; @b]` citation markers in `text`, returning each
+/// marker's byte range and parsed keys.
+pub fn find_citation_markers(text: &str) -> Vec<(Range<usize>, Vec<String>)> {
+    let mut markers = Vec::new();
+    let mut i = 0;
+    while i < text.len() {
+        if text.as_bytes()[i] == b'[' {
+            if let Some(close) = text[i..].find(']') {
+                let inner = &text[i + 1..i + close];
+                if let Some(keys) = parse_citation_keys(inner) {
+                    markers.push((i..i + close + 1, keys));
+                    i += close + 1;
+                    continue;
+                }
+            }
+        }
+        i += 1;
+    }
+    markers
+}
+
+fn parse_citation_keys(inner: &str) -> Option<Vec<String>> {
+    if !inner.starts_with('@') {
+        return None;
+    }
+    let keys: Vec<String> = inner
+        .split(';')
+        .map(|part| part.trim().trim_start_matches('@').to_string())
+        .filter(|key| !key.is_empty() && is_valid_citation_key(key))
+        .collect();
+    if keys.is_empty() {
+        None
+    } else {
+        Some(keys)
+    }
+}
+
+fn is_valid_citation_key(key: &str) -> bool {
+    key.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-' || c == ':')
+}
+
+/// Render a citation marker's keys to an HTML fragment, emitting a
+/// diagnostic for each key not found in `bibliography` (rendered as the raw
+/// `@key` text so the problem is visible in the output).
+///
+/// # Parameters
+/// * `keys` - Citation keys from a single marker (more than one for `[@a; @b]`)
+/// * `bibliography` - Parsed bibliography to resolve keys against
+/// * `style` - Numeric or author-year rendering
+/// * `order` - Shared first-citation order, updated as new keys are seen
+/// * `source_file` - Source file the marker was found in, for diagnostics
+///
+/// # Returns
+/// * `(String, Vec<CitationDiagnostic>)` - Rendered HTML and any unknown-key diagnostics
+pub fn render_citation(
+    keys: &[String],
+    bibliography: &Bibliography,
+    style: CitationStyle,
+    order: &mut CitationOrder,
+    source_file: &Path,
+) -> (String, Vec<CitationDiagnostic>) {
+    let mut diagnostics = Vec::new();
+    let mut parts = Vec::new();
+
+    for key in keys {
+        match bibliography.get(key) {
+            Some(entry) => {
+                let label = match style {
+                    CitationStyle::Numeric => order.number_for(key).to_string(),
+                    CitationStyle::AuthorYear => format_author_year(entry),
+                };
+                parts.push(format!(r#"<a href="#ref-{key}">{label}</a>"#));
+            }
+            None => {
+                diagnostics.push(CitationDiagnostic {
+                    key: key.clone(),
+                    source_file: source_file.to_path_buf(),
+                });
+                parts.push(format!("@{key}"));
+            }
+        }
+    }
+
+    let html = match style {
+        CitationStyle::Numeric => format!("[{}]", parts.join(", ")),
+        CitationStyle::AuthorYear => format!("({})", parts.join("; ")),
+    };
+    (html, diagnostics)
+}
+
+fn format_author_year(entry: &BibEntry) -> String {
+    match (&entry.author, &entry.year) {
+        (Some(author), Some(year)) => format!("{}, {}", first_author_surname(author), year),
+        (Some(author), None) => first_author_surname(author),
+        (None, Some(year)) => year.clone(),
+        (None, None) => entry.key.clone(),
+    }
+}
+
+fn first_author_surname(author: &str) -> String {
+    author
+        .split(" and ")
+        .next()
+        .unwrap_or(author)
+        .split(',')
+        .next()
+        .unwrap_or(author)
+        .trim()
+        .to_string()
+}
+
+/// Build the final "References" section listing only the cited entries.
+///
+/// Numeric style keeps `cited_keys`' first-citation order; author-year style
+/// sorts alphabetically by key. Keys with no matching bibliography entry are
+/// still listed, falling back to the raw key as the reference text.
+///
+/// # Parameters
+/// * `bibliography` - Parsed bibliography to resolve keys against
+/// * `cited_keys` - Keys actually cited in the document, in citation order
+/// * `style` - Numeric or author-year rendering
+/// * `section_number` - Number to assign the synthesized section (typically the last in the document)
+///
+/// # Returns
+/// * `MarkdownSection` - A "References" section ready to append to the document
+pub fn build_references_section(
+    bibliography: &Bibliography,
+    cited_keys: &[String],
+    style: CitationStyle,
+    section_number: SectionNumber,
+) -> MarkdownSection {
+    let mut keys: Vec<&String> = cited_keys.iter().collect();
+    if style == CitationStyle::AuthorYear {
+        keys.sort();
+    }
+
+    let content = keys
+        .into_iter()
+        .enumerate()
+        .map(|(i, key)| MarkdownBlock::ListItem {
+            text: format_reference_entry(bibliography.get(key), key, style, i + 1),
+        })
+        .collect();
+
+    MarkdownSection {
+        heading_level: 1,
+        heading_text: "References".to_string(),
+        section_number,
+        line_number: 0,
+        source_file: PathBuf::new(),
+        content,
+        metadata: None,
+    }
+}
+
+fn format_reference_entry(entry: Option<&BibEntry>, key: &str, style: CitationStyle, index: usize) -> String {
+    let (author, year, title) = match entry {
+        Some(entry) => (
+            entry.author.as_deref().unwrap_or("Unknown author"),
+            entry.year.as_deref().unwrap_or("n.d."),
+            entry.title.as_deref().unwrap_or(key),
+        ),
+        None => ("Unknown author", "n.d.", key),
+    };
+    match style {
+        CitationStyle::Numeric => format!("[{index}] {author} ({year}). {title}."),
+        CitationStyle::AuthorYear => format!("{author} ({year}). {title}."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_BIB: &str = r#"
+@article{smith2020,
+  author = {Smith, John},
+  year = {2020},
+  title = {On Requirements Traceability}
+}
+
+@book{doe2019,
+  author = {Doe, Jane and Roe, Richard},
+  year = {2019},
+  title = {Systems Design}
+}
+"#;
+
+    #[test]
+    fn test_parse_bib_extracts_entries() {
+        let bib = Bibliography::parse(SAMPLE_BIB);
+        let smith = bib.get("smith2020").unwrap();
+        assert_eq!(smith.author.as_deref(), Some("Smith, John"));
+        assert_eq!(smith.year.as_deref(), Some("2020"));
+        assert_eq!(smith.title.as_deref(), Some("On Requirements Traceability"));
+        assert!(bib.get("unknownkey").is_none());
+    }
+
+    #[test]
+    fn test_find_citation_markers_single_and_multiple() {
+        let markers = find_citation_markers("See [@smith2020] and [@doe2019; @smith2020] for details.");
+        assert_eq!(markers.len(), 2);
+        assert_eq!(markers[0].1, vec!["smith2020".to_string()]);
+        assert_eq!(
+            markers[1].1,
+            vec!["doe2019".to_string(), "smith2020".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_find_citation_markers_ignores_non_citation_brackets() {
+        let markers = find_citation_markers("A [normal link](http://example.com) and [not-a-citation].");
+        assert!(markers.is_empty());
+    }
+
+    #[test]
+    fn test_render_citation_numeric_assigns_first_seen_order() {
+        let bib = Bibliography::parse(SAMPLE_BIB);
+        let mut order = CitationOrder::new();
+        let (html_a, diags_a) = render_citation(
+            &["doe2019".to_string()],
+            &bib,
+            CitationStyle::Numeric,
+            &mut order,
+            Path::new("test.md"),
+        );
+        let (html_b, diags_b) = render_citation(
+            &["smith2020".to_string()],
+            &bib,
+            CitationStyle::Numeric,
+            &mut order,
+            Path::new("test.md"),
+        );
+
+        assert!(diags_a.is_empty() && diags_b.is_empty());
+        assert!(html_a.contains('1'));
+        assert!(html_b.contains('2'));
+        assert_eq!(order.cited_keys(), &["doe2019".to_string(), "smith2020".to_string()]);
+    }
+
+    #[test]
+    fn test_render_citation_unknown_key_emits_diagnostic() {
+        let bib = Bibliography::parse(SAMPLE_BIB);
+        let mut order = CitationOrder::new();
+        let (html, diags) = render_citation(
+            &["nobody2099".to_string()],
+            &bib,
+            CitationStyle::Numeric,
+            &mut order,
+            Path::new("test.md"),
+        );
+
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].key, "nobody2099");
+        assert!(html.contains("@nobody2099"));
+    }
+
+    #[test]
+    fn test_build_references_section_numeric_keeps_citation_order() {
+        let bib = Bibliography::parse(SAMPLE_BIB);
+        let section = build_references_section(
+            &bib,
+            &["doe2019".to_string(), "smith2020".to_string()],
+            CitationStyle::Numeric,
+            SectionNumber::parse("9").unwrap(),
+        );
+
+        assert_eq!(section.heading_text, "References");
+        assert_eq!(section.content.len(), 2);
+        match &section.content[0] {
+            MarkdownBlock::ListItem { text } => assert!(text.starts_with("[1]")),
+            other => panic!("expected ListItem, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,397 @@
+//! Image processing pipeline: SVG rasterization and raster downscaling
+//!
+//! Paralleling Zola's `imageproc`, this resolves each [`ImageReference`]'s
+//! path relative to the document root, rasterizes `.drawio.svg`/SVG diagrams
+//! to PNG at a target DPI (the PDF backend doesn't embed SVG cleanly),
+//! downscales oversized raster images while preserving aspect ratio, and
+//! caches results by content hash so unchanged images aren't reprocessed
+//! across builds.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use image::imageops::FilterType;
+use image::GenericImageView;
+use sha2::{Digest, Sha256};
+
+use crate::model::ImageReference;
+
+/// Configuration for the image processing pipeline
+#[derive(Debug, Clone)]
+pub struct ImageProcessingConfig {
+    /// Directory processed images are cached in, keyed by content hash
+    pub cache_dir: PathBuf,
+    /// Maximum width for downscaled raster images, in pixels
+    pub max_width: u32,
+    /// Maximum height for downscaled raster images, in pixels
+    pub max_height: u32,
+    /// Target DPI used when rasterizing SVG diagrams
+    pub svg_dpi: u32,
+}
+
+impl Default for ImageProcessingConfig {
+    fn default() -> Self {
+        Self {
+            cache_dir: PathBuf::from(".sysdoc-cache/images"),
+            max_width: 1600,
+            max_height: 1600,
+            svg_dpi: 150,
+        }
+    }
+}
+
+/// Resolve, process, and cache the image an `ImageReference` points to,
+/// writing the cached path back onto `image.processed_path`.
+///
+/// The cache key hashes the source file's bytes together with whichever
+/// config knobs affect that file's output (`max_width`/`max_height` for
+/// raster images, `svg_dpi` for SVGs), so a previously-processed image is
+/// only reused when both its content *and* the settings it would be
+/// reprocessed under are unchanged; rerunning with a different DPI or max
+/// dimension reprocesses instead of silently returning stale output.
+///
+/// # Parameters
+/// * `image` - Image reference to process; `processed_path` is set on success
+/// * `root` - Document root directory the image's `url` is relative to
+/// * `config` - Processing limits and cache location
+///
+/// # Returns
+/// * `io::Result<()>` - `Err` if the source file can't be read or decoded
+pub fn process_image(
+    image: &mut ImageReference,
+    root: &Path,
+    config: &ImageProcessingConfig,
+) -> io::Result<()> {
+    let source_path = root.join(&image.url);
+    let source_bytes = fs::read(&source_path)?;
+
+    let is_svg = is_svg_path(&source_path);
+    let config_fingerprint = if is_svg {
+        format!("dpi={}", config.svg_dpi)
+    } else {
+        format!("max={}x{}", config.max_width, config.max_height)
+    };
+    let hash = content_hash(&[&source_bytes, config_fingerprint.as_bytes()]);
+
+    fs::create_dir_all(&config.cache_dir)?;
+
+    let cached_extension = if is_svg { "png" } else { extension_of(&source_path) };
+    let cached_path = config.cache_dir.join(format!("{hash}.{cached_extension}"));
+
+    if !cached_path.exists() {
+        if is_svg {
+            rasterize_svg(&source_bytes, &cached_path, config.svg_dpi)?;
+        } else {
+            downscale_raster(&source_bytes, &cached_path, config.max_width, config.max_height)?;
+        }
+    }
+
+    image.processed_path = Some(cached_path);
+    Ok(())
+}
+
+/// Hash the concatenation of `parts` so cache keys can fold in config knobs
+/// (max dimensions, DPI) alongside source bytes without a second read pass.
+fn content_hash(parts: &[&[u8]]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+fn is_svg_path(path: &Path) -> bool {
+    path.to_string_lossy().to_lowercase().ends_with(".svg")
+}
+
+fn extension_of(path: &Path) -> &str {
+    path.extension().and_then(|ext| ext.to_str()).unwrap_or("png")
+}
+
+/// Rasterize SVG bytes to a PNG file at the given DPI (96 DPI is the SVG
+/// reference resolution, so `dpi / 96` gives the scale factor)
+fn rasterize_svg(svg_bytes: &[u8], out_path: &Path, dpi: u32) -> io::Result<()> {
+    let options = usvg::Options::default();
+    let tree = usvg::Tree::from_data(svg_bytes, &options)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+
+    let scale = dpi as f32 / 96.0;
+    let size = tree
+        .size()
+        .to_int_size()
+        .scale_by(scale)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid SVG dimensions"))?;
+
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "could not allocate pixmap"))?;
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    pixmap
+        .save_png(out_path)
+        .map_err(|err| io::Error::new(io::ErrorKind::Other, err.to_string()))
+}
+
+/// Downscale a raster image to fit within `max_width` x `max_height`,
+/// preserving aspect ratio. Images already within bounds are cached as-is.
+fn downscale_raster(
+    bytes: &[u8],
+    out_path: &Path,
+    max_width: u32,
+    max_height: u32,
+) -> io::Result<()> {
+    let decoded = image::load_from_memory(bytes)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+    let (width, height) = decoded.dimensions();
+
+    if width <= max_width && height <= max_height {
+        return fs::write(out_path, bytes);
+    }
+
+    decoded
+        .resize(max_width, max_height, FilterType::Lanczos3)
+        .save(out_path)
+        .map_err(|err| io::Error::new(io::ErrorKind::Other, err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    /// A unique scratch file path under the system temp dir, so concurrent
+    /// test runs don't collide on the same cache/source files.
+    fn temp_path(name: &str) -> PathBuf {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        std::env::temp_dir().join(format!("sysdoc-imageproc-test-{}-{n}-{name}", std::process::id()))
+    }
+
+    fn encode_png(width: u32, height: u32) -> Vec<u8> {
+        let img = image::RgbaImage::from_fn(width, height, |x, y| {
+            image::Rgba([(x % 256) as u8, (y % 256) as u8, 0, 255])
+        });
+        let mut bytes = Vec::new();
+        image::DynamicImage::ImageRgba8(img)
+            .write_to(&mut std::io::Cursor::new(&mut bytes), image::ImageFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_downscale_raster_shrinks_to_bounds_preserving_aspect_ratio() {
+        let bytes = encode_png(4000, 2000);
+        let out_path = temp_path("downscaled.png");
+
+        downscale_raster(&bytes, &out_path, 1600, 1600).unwrap();
+
+        let decoded = image::open(&out_path).unwrap();
+        let (width, height) = decoded.dimensions();
+        assert!(width <= 1600 && height <= 1600);
+        // 4000x2000 is 2:1 - the downscaled image should preserve that ratio
+        assert_eq!(width, 1600);
+        assert_eq!(height, 800);
+
+        fs::remove_file(&out_path).ok();
+    }
+
+    #[test]
+    fn test_downscale_raster_leaves_in_bounds_image_untouched() {
+        let bytes = encode_png(100, 50);
+        let out_path = temp_path("unchanged.png");
+
+        downscale_raster(&bytes, &out_path, 1600, 1600).unwrap();
+
+        let decoded = image::open(&out_path).unwrap();
+        assert_eq!(decoded.dimensions(), (100, 50));
+
+        fs::remove_file(&out_path).ok();
+    }
+
+    #[test]
+    fn test_rasterize_svg_produces_decodable_png() {
+        let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="50"><rect width="100" height="50" fill="red"/></svg>"#;
+        let out_path = temp_path("rasterized.png");
+
+        rasterize_svg(svg, &out_path, 96).unwrap();
+
+        let decoded = image::open(&out_path).unwrap();
+        let (width, height) = decoded.dimensions();
+        assert_eq!((width, height), (100, 50));
+
+        fs::remove_file(&out_path).ok();
+    }
+
+    #[test]
+    fn test_rasterize_svg_scales_by_dpi() {
+        let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="50"></svg>"#;
+        let out_path = temp_path("rasterized-hidpi.png");
+
+        // 192 DPI is double the 96 DPI SVG reference resolution
+        rasterize_svg(svg, &out_path, 192).unwrap();
+
+        let decoded = image::open(&out_path).unwrap();
+        assert_eq!(decoded.dimensions(), (200, 100));
+
+        fs::remove_file(&out_path).ok();
+    }
+
+    #[test]
+    fn test_process_image_skips_reprocessing_unchanged_source() {
+        let root = temp_path("cache-hit-root");
+        fs::create_dir_all(&root).unwrap();
+        let source_path = root.join("diagram.png");
+        fs::write(&source_path, encode_png(4000, 2000)).unwrap();
+
+        let config = ImageProcessingConfig {
+            cache_dir: temp_path("cache-hit-cache"),
+            max_width: 1600,
+            max_height: 1600,
+            svg_dpi: 150,
+        };
+
+        let mut image = ImageReference {
+            url: "diagram.png".to_string(),
+            alt_text: String::new(),
+            processed_path: None,
+        };
+
+        process_image(&mut image, &root, &config).unwrap();
+        let cached_path = image.processed_path.clone().unwrap();
+        let first_modified = fs::metadata(&cached_path).unwrap().modified().unwrap();
+
+        // Reprocessing would rewrite the file via a fresh `resize`+`save`;
+        // instead the cache hit should leave it untouched.
+        process_image(&mut image, &root, &config).unwrap();
+        let second_modified = fs::metadata(&cached_path).unwrap().modified().unwrap();
+
+        assert_eq!(image.processed_path, Some(cached_path));
+        assert_eq!(first_modified, second_modified);
+
+        fs::remove_dir_all(&root).ok();
+        fs::remove_dir_all(&config.cache_dir).ok();
+    }
+
+    #[test]
+    fn test_process_image_reprocesses_raster_when_max_width_changes() {
+        let root = temp_path("reconfig-raster-root");
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("diagram.png"), encode_png(4000, 2000)).unwrap();
+        let cache_dir = temp_path("reconfig-raster-cache");
+
+        let mut image = ImageReference {
+            url: "diagram.png".to_string(),
+            alt_text: String::new(),
+            processed_path: None,
+        };
+
+        let narrow = ImageProcessingConfig {
+            cache_dir: cache_dir.clone(),
+            max_width: 800,
+            max_height: 800,
+            svg_dpi: 150,
+        };
+        process_image(&mut image, &root, &narrow).unwrap();
+        let narrow_path = image.processed_path.clone().unwrap();
+        let narrow_dimensions = image::open(&narrow_path).unwrap().dimensions();
+
+        let wide = ImageProcessingConfig {
+            max_width: 1600,
+            max_height: 1600,
+            ..narrow.clone()
+        };
+        process_image(&mut image, &root, &wide).unwrap();
+        let wide_path = image.processed_path.clone().unwrap();
+        let wide_dimensions = image::open(&wide_path).unwrap().dimensions();
+
+        assert_ne!(narrow_path, wide_path, "different max_width must use a different cache entry");
+        assert_ne!(narrow_dimensions, wide_dimensions);
+        assert_eq!(wide_dimensions, (1600, 800));
+
+        fs::remove_dir_all(&root).ok();
+        fs::remove_dir_all(&cache_dir).ok();
+    }
+
+    #[test]
+    fn test_process_image_reprocesses_svg_when_dpi_changes() {
+        let root = temp_path("reconfig-svg-root");
+        fs::create_dir_all(&root).unwrap();
+        let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="50"></svg>"#;
+        fs::write(root.join("diagram.svg"), svg).unwrap();
+        let cache_dir = temp_path("reconfig-svg-cache");
+
+        let mut image = ImageReference {
+            url: "diagram.svg".to_string(),
+            alt_text: String::new(),
+            processed_path: None,
+        };
+
+        let low_dpi = ImageProcessingConfig {
+            cache_dir: cache_dir.clone(),
+            max_width: 1600,
+            max_height: 1600,
+            svg_dpi: 96,
+        };
+        process_image(&mut image, &root, &low_dpi).unwrap();
+        let low_dpi_path = image.processed_path.clone().unwrap();
+        let low_dpi_dimensions = image::open(&low_dpi_path).unwrap().dimensions();
+
+        let high_dpi = ImageProcessingConfig {
+            svg_dpi: 192,
+            ..low_dpi.clone()
+        };
+        process_image(&mut image, &root, &high_dpi).unwrap();
+        let high_dpi_path = image.processed_path.clone().unwrap();
+        let high_dpi_dimensions = image::open(&high_dpi_path).unwrap().dimensions();
+
+        assert_ne!(low_dpi_path, high_dpi_path, "different svg_dpi must use a different cache entry");
+        assert_eq!(low_dpi_dimensions, (100, 50));
+        assert_eq!(high_dpi_dimensions, (200, 100));
+
+        fs::remove_dir_all(&root).ok();
+        fs::remove_dir_all(&cache_dir).ok();
+    }
+
+    #[test]
+    fn test_is_svg_path() {
+        assert!(is_svg_path(Path::new("diagrams/system.svg")));
+        assert!(is_svg_path(Path::new("diagrams/system.drawio.svg")));
+        assert!(!is_svg_path(Path::new("images/screenshot.png")));
+    }
+
+    #[test]
+    fn test_extension_of() {
+        assert_eq!(extension_of(Path::new("a/b.png")), "png");
+        assert_eq!(extension_of(Path::new("a/b")), "png");
+    }
+
+    #[test]
+    fn test_content_hash_is_stable_and_content_sensitive() {
+        let hash_a = content_hash(&[b"hello"]);
+        let hash_b = content_hash(&[b"hello"]);
+        let hash_c = content_hash(&[b"world"]);
+
+        assert_eq!(hash_a, hash_b);
+        assert_ne!(hash_a, hash_c);
+    }
+
+    #[test]
+    fn test_content_hash_is_sensitive_to_every_part() {
+        let hash_a = content_hash(&[b"hello", b"max=100x100"]);
+        let hash_b = content_hash(&[b"hello", b"max=200x200"]);
+
+        assert_ne!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn test_default_config_has_sensible_limits() {
+        let config = ImageProcessingConfig::default();
+        assert!(config.max_width > 0);
+        assert!(config.max_height > 0);
+        assert!(config.svg_dpi > 0);
+    }
+}
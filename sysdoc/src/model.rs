@@ -1,8 +1,26 @@
 //! Document model for representing parsed markdown documents
 
-use pulldown_cmark::{Event, Parser, Tag};
+use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag, TagEnd, TextMergeStream};
 use std::fmt;
 use std::path::PathBuf;
+use std::sync::OnceLock;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::ThemeSet;
+use syntect::html::{styled_line_to_highlighted_html, IncludeBackground};
+use syntect::parsing::SyntaxSet;
+
+use crate::citations::{
+    find_citation_markers, render_citation, Bibliography, CitationDiagnostic, CitationOrder,
+    CitationStyle,
+};
+use crate::source_model::MarkdownBlock;
+
+/// Language tag used by `sysdoc` metadata code blocks; these are never
+/// syntax-highlighted and are left for the metadata parser to handle.
+const SYSDOC_METADATA_LANG: &str = "sysdoc";
+
+/// Default syntect theme used when a document doesn't configure one
+pub const DEFAULT_SYNTAX_THEME: &str = "InspiredGitHub";
 
 /// Represents the entire document being built
 #[derive(Debug)]
@@ -41,6 +59,8 @@ pub struct Section {
     pub images: Vec<ImageReference>,
     /// Table references found in the markdown (CSV files)
     pub tables: Vec<PathBuf>,
+    /// Syntax-highlighted fenced code blocks found in the markdown (excludes `sysdoc` metadata blocks)
+    pub code_blocks: Vec<MarkdownBlock>,
     /// Path to source file (for error reporting)
     #[allow(dead_code)]
     pub source_path: PathBuf,
@@ -48,19 +68,88 @@ pub struct Section {
 
 impl Section {
     /// Parse the markdown content and extract references
-    pub fn parse_content(&mut self) {
-        let parser = Parser::new(&self.content);
+    ///
+    /// # Parameters
+    /// * `syntax_theme` - Name of the bundled syntect theme to highlight fenced code blocks with
+    /// * `citations` - Bibliography to resolve inline `[@key]` markers against, if the document has one
+    /// * `citation_style` - Numeric or author-year rendering for resolved citations
+    /// * `citation_order` - Shared first-citation order, updated as new keys are seen across sections
+    ///
+    /// # Returns
+    /// * `Vec<CitationDiagnostic>` - One entry per citation marker that referenced an unknown key
+    pub fn parse_content(
+        &mut self,
+        syntax_theme: &str,
+        citations: Option<&Bibliography>,
+        citation_style: CitationStyle,
+        citation_order: &mut CitationOrder,
+    ) -> Vec<CitationDiagnostic> {
+        // The parser emits unresolved `[`/`]` as separate Text events; merge
+        // adjacent Text so a whole `[@key]` marker reaches the citation scan.
+        let parser = TextMergeStream::new(Parser::new(&self.content));
         let mut events = Vec::new();
         let mut images = Vec::new();
         let mut tables = Vec::new();
+        let mut code_blocks = Vec::new();
+        let mut citation_diagnostics = Vec::new();
+        let mut current_code: Option<(Option<String>, String)> = None;
+        // Tracks "inside any fenced code block", including `sysdoc` metadata
+        // blocks, which never populate `current_code`. Citation scanning must
+        // stay off for the entire fence, not just the ones we highlight.
+        let mut in_fenced_code = false;
 
         for event in parser {
             match &event {
+                Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(lang))) => {
+                    in_fenced_code = true;
+                    let lang = lang.to_string();
+                    if lang != SYSDOC_METADATA_LANG {
+                        let lang = if lang.is_empty() { None } else { Some(lang) };
+                        current_code = Some((lang, String::new()));
+                    }
+                }
+                Event::Text(text) => {
+                    if in_fenced_code {
+                        if let Some((_, raw)) = current_code.as_mut() {
+                            raw.push_str(text);
+                        }
+                    } else if let Some(bibliography) = citations {
+                        let markers = find_citation_markers(text);
+                        if !markers.is_empty() {
+                            push_citation_events(
+                                text,
+                                &markers,
+                                bibliography,
+                                citation_style,
+                                citation_order,
+                                &self.source_path,
+                                &mut events,
+                                &mut citation_diagnostics,
+                            );
+                            continue;
+                        }
+                    }
+                }
+                Event::End(TagEnd::CodeBlock) => {
+                    in_fenced_code = false;
+                    if let Some((lang, raw)) = current_code.take() {
+                        let highlighted_html = match &lang {
+                            Some(lang) => highlight_code(&raw, lang, syntax_theme),
+                            None => html_escape(&raw),
+                        };
+                        code_blocks.push(MarkdownBlock::Code {
+                            lang,
+                            highlighted_html,
+                            raw,
+                        });
+                    }
+                }
                 Event::Start(Tag::Image { dest_url, .. }) => {
                     let url = dest_url.to_string();
                     images.push(ImageReference {
                         url: url.clone(),
                         alt_text: String::new(), // Will be filled when we see the text
+                        processed_path: None,
                     });
                 }
                 Event::Start(Tag::Link { dest_url, .. }) => {
@@ -79,9 +168,87 @@ impl Section {
         self.events = events;
         self.images = images;
         self.tables = tables;
+        self.code_blocks = code_blocks;
+        citation_diagnostics
     }
 }
 
+/// Split `text` around its citation markers, rendering each marker to inline
+/// HTML and pushing the literal/rendered pieces onto `events` in order.
+#[allow(clippy::too_many_arguments)]
+fn push_citation_events(
+    text: &str,
+    markers: &[(std::ops::Range<usize>, Vec<String>)],
+    bibliography: &Bibliography,
+    citation_style: CitationStyle,
+    citation_order: &mut CitationOrder,
+    source_path: &std::path::Path,
+    events: &mut Vec<Event<'static>>,
+    citation_diagnostics: &mut Vec<CitationDiagnostic>,
+) {
+    let mut last = 0;
+    for (range, keys) in markers {
+        if range.start > last {
+            events.push(Event::Text(text[last..range.start].to_string().into()));
+        }
+        let (html, mut diagnostics) =
+            render_citation(keys, bibliography, citation_style, citation_order, source_path);
+        events.push(Event::InlineHtml(html.into()));
+        citation_diagnostics.append(&mut diagnostics);
+        last = range.end;
+    }
+    if last < text.len() {
+        events.push(Event::Text(text[last..].to_string().into()));
+    }
+}
+
+fn syntax_set() -> &'static SyntaxSet {
+    static SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SET.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+fn theme_set() -> &'static ThemeSet {
+    static SET: OnceLock<ThemeSet> = OnceLock::new();
+    SET.get_or_init(ThemeSet::load_defaults)
+}
+
+/// Render a fenced code block's contents to highlighted HTML using syntect
+///
+/// Unrecognized languages are highlighted with the plain-text syntax; falls
+/// back to plain (HTML-escaped) text when the theme isn't recognized or a
+/// line fails to highlight.
+fn highlight_code(code: &str, lang: &str, theme_name: &str) -> String {
+    let syntax_set = syntax_set();
+    let Some(theme) = theme_set().themes.get(theme_name) else {
+        return html_escape(code);
+    };
+    let syntax = syntax_set
+        .find_syntax_by_token(lang)
+        .unwrap_or_else(|| syntax_set.find_syntax_plain_text());
+
+    let mut highlighter = HighlightLines::new(syntax, theme);
+    let mut html = String::new();
+    for line in code.lines() {
+        let Ok(ranges) = highlighter.highlight_line(line, syntax_set) else {
+            return html_escape(code);
+        };
+        let Ok(line_html) = styled_line_to_highlighted_html(&ranges[..], IncludeBackground::No)
+        else {
+            return html_escape(code);
+        };
+        html.push_str(&line_html);
+        html.push('\n');
+    }
+    html
+}
+
+/// Escape text for safe inclusion as HTML body content
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
 /// Reference to an image in the markdown
 #[derive(Debug, Clone)]
 pub struct ImageReference {
@@ -91,6 +258,9 @@ pub struct ImageReference {
     /// Alt text for the image
     #[allow(dead_code)]
     pub alt_text: String,
+    /// Path to the rasterized/downscaled, cache-keyed version of this image,
+    /// set by [`crate::imageproc::process_image`]. `None` until processed.
+    pub processed_path: Option<PathBuf>,
 }
 
 /// Section number representation
@@ -151,4 +321,133 @@ mod tests {
         assert!(num1 < num2);
         assert!(num2 < num3);
     }
+
+    fn test_section(content: &str) -> Section {
+        Section {
+            number: SectionNumber::parse("01").unwrap(),
+            title: "Test".to_string(),
+            depth: 0,
+            content: content.to_string(),
+            events: Vec::new(),
+            images: Vec::new(),
+            tables: Vec::new(),
+            code_blocks: Vec::new(),
+            source_path: PathBuf::from("test.md"),
+        }
+    }
+
+    #[test]
+    fn test_parse_content_highlights_fenced_code_block() {
+        let mut section = test_section("```rust\nfn main() {}\n```\n");
+        section.parse_content(
+            DEFAULT_SYNTAX_THEME,
+            None,
+            CitationStyle::Numeric,
+            &mut CitationOrder::new(),
+        );
+
+        assert_eq!(section.code_blocks.len(), 1);
+        match &section.code_blocks[0] {
+            MarkdownBlock::Code {
+                lang,
+                raw,
+                highlighted_html,
+            } => {
+                assert_eq!(lang.as_deref(), Some("rust"));
+                assert_eq!(raw, "fn main() {}\n");
+                assert!(!highlighted_html.is_empty());
+            }
+            other => panic!("expected MarkdownBlock::Code, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_content_skips_sysdoc_metadata_block() {
+        let mut section = test_section("```sysdoc\nsection_id = \"REQ-001\"\n```\n");
+        section.parse_content(
+            DEFAULT_SYNTAX_THEME,
+            None,
+            CitationStyle::Numeric,
+            &mut CitationOrder::new(),
+        );
+
+        assert!(section.code_blocks.is_empty());
+    }
+
+    #[test]
+    fn test_parse_content_unknown_language_falls_back_to_plaintext() {
+        let mut section = test_section("```not-a-real-language\nhello\n```\n");
+        section.parse_content(
+            DEFAULT_SYNTAX_THEME,
+            None,
+            CitationStyle::Numeric,
+            &mut CitationOrder::new(),
+        );
+
+        assert_eq!(section.code_blocks.len(), 1);
+        match &section.code_blocks[0] {
+            MarkdownBlock::Code { highlighted_html, .. } => {
+                assert!(highlighted_html.contains("hello"));
+            }
+            other => panic!("expected MarkdownBlock::Code, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_content_resolves_known_citation() {
+        let bibliography = Bibliography::parse(
+            r#"@article{smith2020, author = {Smith, John}, year = {2020}, title = {A Paper}}"#,
+        );
+        let mut section = test_section("See [@smith2020] for details.\n");
+        let diagnostics = section.parse_content(
+            DEFAULT_SYNTAX_THEME,
+            Some(&bibliography),
+            CitationStyle::Numeric,
+            &mut CitationOrder::new(),
+        );
+
+        assert!(diagnostics.is_empty());
+        let has_citation_html = section
+            .events
+            .iter()
+            .any(|event| matches!(event, Event::InlineHtml(html) if html.contains("#ref-smith2020")));
+        assert!(has_citation_html);
+    }
+
+    #[test]
+    fn test_parse_content_unknown_citation_emits_diagnostic() {
+        let bibliography = Bibliography::parse("");
+        let mut section = test_section("See [@nobody2099] for details.\n");
+        let diagnostics = section.parse_content(
+            DEFAULT_SYNTAX_THEME,
+            Some(&bibliography),
+            CitationStyle::Numeric,
+            &mut CitationOrder::new(),
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].key, "nobody2099");
+    }
+
+    #[test]
+    fn test_parse_content_does_not_scan_citations_inside_sysdoc_block() {
+        let bibliography = Bibliography::parse(
+            r#"@article{smith2020, author = {Smith, John}, year = {2020}, title = {A Paper}}"#,
+        );
+        let mut section = test_section("```sysdoc\ntraced_ids = [\"[@smith2020]\"]\n```\n");
+        let diagnostics = section.parse_content(
+            DEFAULT_SYNTAX_THEME,
+            Some(&bibliography),
+            CitationStyle::Numeric,
+            &mut CitationOrder::new(),
+        );
+
+        assert!(diagnostics.is_empty());
+        assert!(section.code_blocks.is_empty());
+        let has_citation_html = section
+            .events
+            .iter()
+            .any(|event| matches!(event, Event::InlineHtml(_)));
+        assert!(!has_citation_html);
+    }
 }
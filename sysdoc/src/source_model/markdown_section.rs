@@ -0,0 +1,207 @@
+//! Stage 2 markdown section and block representation.
+
+use std::path::PathBuf;
+
+use super::SectionMetadata;
+use crate::model::SectionNumber;
+
+/// A section of the unified document, corresponding to one heading-delimited
+/// chunk of a source markdown file.
+#[derive(Debug, Clone)]
+pub struct MarkdownSection {
+    /// Heading level (1-6) the section was introduced at
+    pub heading_level: u8,
+    /// Heading text
+    pub heading_text: String,
+    /// Section number derived from the source file's position in the document
+    pub section_number: SectionNumber,
+    /// Line number in the source file where the heading starts
+    pub line_number: usize,
+    /// Source file this section was parsed from
+    pub source_file: PathBuf,
+    /// Parsed content blocks belonging to this section
+    pub content: Vec<MarkdownBlock>,
+    /// Optional traceability metadata parsed from a `sysdoc` code block
+    pub metadata: Option<SectionMetadata>,
+}
+
+impl MarkdownSection {
+    /// Count the words in this section's text-bearing content.
+    ///
+    /// Code blocks are always excluded. Image alt text is excluded by
+    /// default; pass `include_image_alt = true` to count it too.
+    ///
+    /// # Parameters
+    /// * `include_image_alt` - Whether to count words in image alt text
+    ///
+    /// # Returns
+    /// * `usize` - Number of whitespace-separated words in this section
+    pub fn word_count(&self, include_image_alt: bool) -> usize {
+        self.content
+            .iter()
+            .map(|block| block.word_count(include_image_alt))
+            .sum()
+    }
+
+    /// Estimate the reading time for this section alone.
+    ///
+    /// Computed as `ceil(word_count / words_per_minute)`, mirroring
+    /// [`UnifiedDocument::reading_time_minutes`](crate::unified_document::UnifiedDocument::reading_time_minutes)
+    /// so per-section and document totals agree on how a word count turns into minutes.
+    ///
+    /// # Parameters
+    /// * `include_image_alt` - Whether to count words in image alt text
+    /// * `words_per_minute` - Reading speed in words per minute
+    ///
+    /// # Returns
+    /// * `u32` - Estimated reading time in minutes (0 if the section has no words)
+    pub fn reading_time_minutes(&self, include_image_alt: bool, words_per_minute: u32) -> u32 {
+        crate::unified_document::reading_time_minutes(self.word_count(include_image_alt), words_per_minute)
+    }
+}
+
+/// A single block of content within a [`MarkdownSection`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarkdownBlock {
+    /// A heading within the section body (not the section's own heading)
+    Heading { level: u8, text: String },
+    /// A paragraph of text
+    Paragraph { text: String },
+    /// A single list item
+    ListItem { text: String },
+    /// A table with a header row and data rows
+    Table {
+        headers: Vec<String>,
+        rows: Vec<Vec<String>>,
+    },
+    /// An embedded image
+    Image { url: String, alt_text: String },
+    /// A fenced code block, syntax-highlighted at parse time
+    Code {
+        /// Fence language tag, if any (e.g. "rust")
+        lang: Option<String>,
+        /// Syntax-highlighted HTML for the code block body
+        highlighted_html: String,
+        /// Raw, unhighlighted code as written in the source
+        raw: String,
+    },
+}
+
+impl MarkdownBlock {
+    /// Count the words contributed by this block.
+    ///
+    /// Code blocks never contribute words. Image alt text only contributes
+    /// when `include_image_alt` is set.
+    fn word_count(&self, include_image_alt: bool) -> usize {
+        match self {
+            MarkdownBlock::Heading { text, .. } => count_words(text),
+            MarkdownBlock::Paragraph { text } => count_words(text),
+            MarkdownBlock::ListItem { text } => count_words(text),
+            MarkdownBlock::Table { headers, rows } => {
+                let header_words: usize = headers.iter().map(|cell| count_words(cell)).sum();
+                let row_words: usize = rows
+                    .iter()
+                    .flat_map(|row| row.iter())
+                    .map(|cell| count_words(cell))
+                    .sum();
+                header_words + row_words
+            }
+            MarkdownBlock::Image { alt_text, .. } => {
+                if include_image_alt {
+                    count_words(alt_text)
+                } else {
+                    0
+                }
+            }
+            MarkdownBlock::Code { .. } => 0,
+        }
+    }
+}
+
+/// Split text on Unicode whitespace and count the resulting words.
+fn count_words(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_count_excludes_code_blocks() {
+        let section = MarkdownSection {
+            heading_level: 1,
+            heading_text: "Example".to_string(),
+            section_number: SectionNumber::parse("1").unwrap(),
+            line_number: 1,
+            source_file: PathBuf::from("test.md"),
+            content: vec![
+                MarkdownBlock::Paragraph {
+                    text: "four words right here".to_string(),
+                },
+                MarkdownBlock::Code {
+                    lang: Some("rust".to_string()),
+                    highlighted_html: "<span>fn main() { println!(\"hello world\"); }</span>"
+                        .to_string(),
+                    raw: "fn main() { println!(\"hello world\"); }".to_string(),
+                },
+            ],
+            metadata: None,
+        };
+
+        assert_eq!(section.word_count(false), 4);
+    }
+
+    #[test]
+    fn test_word_count_image_alt_text_opt_in() {
+        let section = MarkdownSection {
+            heading_level: 1,
+            heading_text: "Example".to_string(),
+            section_number: SectionNumber::parse("1").unwrap(),
+            line_number: 1,
+            source_file: PathBuf::from("test.md"),
+            content: vec![MarkdownBlock::Image {
+                url: "diagram.png".to_string(),
+                alt_text: "system diagram".to_string(),
+            }],
+            metadata: None,
+        };
+
+        assert_eq!(section.word_count(false), 0);
+        assert_eq!(section.word_count(true), 2);
+    }
+
+    #[test]
+    fn test_reading_time_minutes_rounds_up_from_word_count() {
+        let words: Vec<&str> = vec!["word"; 201];
+        let section = MarkdownSection {
+            heading_level: 1,
+            heading_text: "Example".to_string(),
+            section_number: SectionNumber::parse("1").unwrap(),
+            line_number: 1,
+            source_file: PathBuf::from("test.md"),
+            content: vec![MarkdownBlock::Paragraph {
+                text: words.join(" "),
+            }],
+            metadata: None,
+        };
+
+        assert_eq!(section.reading_time_minutes(false, 200), 2);
+        assert_eq!(section.reading_time_minutes(false, 1), 201);
+    }
+
+    #[test]
+    fn test_reading_time_minutes_zero_words() {
+        let section = MarkdownSection {
+            heading_level: 1,
+            heading_text: "Example".to_string(),
+            section_number: SectionNumber::parse("1").unwrap(),
+            line_number: 1,
+            source_file: PathBuf::from("test.md"),
+            content: vec![],
+            metadata: None,
+        };
+
+        assert_eq!(section.reading_time_minutes(false, 200), 0);
+    }
+}
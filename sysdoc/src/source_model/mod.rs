@@ -0,0 +1,13 @@
+//! Stage 2 source model: the aggregated representation of parsed markdown
+//! sections, ready to be assembled into a [`crate::unified_document::UnifiedDocument`].
+
+mod markdown_section;
+mod table_source;
+
+pub mod section_metadata;
+
+pub use markdown_section::{MarkdownBlock, MarkdownSection};
+pub use section_metadata::SectionMetadata;
+pub use table_source::TableSource;
+
+pub use crate::model::SectionNumber;
@@ -0,0 +1,14 @@
+//! Table sources backed by CSV files embedded in a document.
+
+use std::path::PathBuf;
+
+/// A table referenced from markdown content, backed by a CSV file on disk.
+#[derive(Debug, Clone)]
+pub struct TableSource {
+    /// Path to the CSV file, relative to the document root
+    pub path: PathBuf,
+    /// Table header row
+    pub headers: Vec<String>,
+    /// Table data rows
+    pub rows: Vec<Vec<String>>,
+}
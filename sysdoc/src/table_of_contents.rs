@@ -0,0 +1,238 @@
+//! Table of contents generation with stable, collision-resistant anchors
+//!
+//! After sections are aggregated and sorted by `SectionNumber`, builds a
+//! nested TOC tree from section headings keyed by the numbering depth
+//! (`1`, `1.1`, `1.1.1`), alongside slugified anchor ids exporters can link
+//! to. A flat list is also produced for backends (like the PDF exporter)
+//! that want bookmarks/outline entries rather than a nested structure.
+
+use std::collections::HashSet;
+
+use crate::source_model::MarkdownSection;
+use crate::unified_document::UnifiedDocument;
+
+/// A single entry in the table of contents
+#[derive(Debug, Clone, PartialEq)]
+pub struct TocEntry {
+    /// Section heading text
+    pub title: String,
+    /// Dotted section number (e.g. "1.2.1")
+    pub number: String,
+    /// Heading nesting depth (0 = top level)
+    pub depth: usize,
+    /// Slugified, collision-resistant anchor id for this heading
+    pub anchor: String,
+    /// Nested child entries (empty for [`build_flat_toc`] output)
+    pub children: Vec<TocEntry>,
+}
+
+/// Build a nested table of contents from a document's sorted sections,
+/// suitable for rendering as nested `<ul>` links in HTML output.
+///
+/// # Parameters
+/// * `document` - Document whose sections are already sorted by `SectionNumber`
+///
+/// # Returns
+/// * `Vec<TocEntry>` - Top-level entries, each with nested `children`
+pub fn build_toc(document: &UnifiedDocument) -> Vec<TocEntry> {
+    nest(flat_entries(document))
+}
+
+/// Build a flat table of contents from a document's sorted sections,
+/// suitable for PDF bookmarks/outline entries where nesting is expressed via
+/// `depth` rather than tree structure.
+///
+/// # Parameters
+/// * `document` - Document whose sections are already sorted by `SectionNumber`
+///
+/// # Returns
+/// * `Vec<TocEntry>` - One entry per section, in document order, `children` always empty
+pub fn build_flat_toc(document: &UnifiedDocument) -> Vec<TocEntry> {
+    flat_entries(document)
+}
+
+fn flat_entries(document: &UnifiedDocument) -> Vec<TocEntry> {
+    let mut slugger = AnchorSlugger::default();
+    document
+        .sections
+        .iter()
+        .map(|section| flat_entry(section, &mut slugger))
+        .collect()
+}
+
+fn flat_entry(section: &MarkdownSection, slugger: &mut AnchorSlugger) -> TocEntry {
+    TocEntry {
+        title: section.heading_text.clone(),
+        number: section.section_number.to_string(),
+        depth: section.section_number.depth(),
+        anchor: slugger.slug_for(&section.heading_text),
+        children: Vec::new(),
+    }
+}
+
+/// Nest a depth-ordered, flat list of entries into a tree by `depth` (0 =
+/// top level). Each entry is attached as a child of the nearest preceding
+/// entry with a strictly shallower depth, so gaps in depth (e.g. `1` then
+/// `1.1.1`) still nest correctly rather than requiring exact depth+1 steps.
+fn nest(entries: Vec<TocEntry>) -> Vec<TocEntry> {
+    let mut roots: Vec<TocEntry> = Vec::new();
+    let mut open: Vec<TocEntry> = Vec::new();
+
+    for entry in entries {
+        while let Some(top) = open.last() {
+            if top.depth >= entry.depth {
+                let finished = open.pop().unwrap();
+                attach(&mut open, &mut roots, finished);
+            } else {
+                break;
+            }
+        }
+        open.push(entry);
+    }
+    while let Some(finished) = open.pop() {
+        attach(&mut open, &mut roots, finished);
+    }
+
+    roots
+}
+
+fn attach(open: &mut [TocEntry], roots: &mut Vec<TocEntry>, entry: TocEntry) {
+    match open.last_mut() {
+        Some(parent) => parent.children.push(entry),
+        None => roots.push(entry),
+    }
+}
+
+/// Assigns slugified heading anchors, appending `-1`, `-2`, ... on collision
+#[derive(Default)]
+struct AnchorSlugger {
+    seen: HashSet<String>,
+}
+
+impl AnchorSlugger {
+    fn slug_for(&mut self, text: &str) -> String {
+        let base = slugify(text);
+        if self.seen.insert(base.clone()) {
+            return base;
+        }
+
+        let mut suffix = 1;
+        loop {
+            let candidate = format!("{base}-{suffix}");
+            if self.seen.insert(candidate.clone()) {
+                return candidate;
+            }
+            suffix += 1;
+        }
+    }
+}
+
+/// Slugify heading text: lowercase alphanumerics joined by single hyphens,
+/// with non-alphanumeric runs collapsed to one hyphen and leading/trailing
+/// hyphens trimmed.
+fn slugify(text: &str) -> String {
+    let mut slug = String::new();
+    let mut last_was_hyphen = true;
+
+    for ch in text.chars() {
+        if ch.is_alphanumeric() {
+            slug.extend(ch.to_lowercase());
+            last_was_hyphen = false;
+        } else if !last_was_hyphen {
+            slug.push('-');
+            last_was_hyphen = true;
+        }
+    }
+
+    match slug.trim_end_matches('-') {
+        "" => "section".to_string(),
+        trimmed => trimmed.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source_model::SectionNumber;
+    use crate::test_support::test_metadata;
+    use std::path::PathBuf;
+
+    fn section(number: &str, heading_text: &str) -> MarkdownSection {
+        MarkdownSection {
+            heading_level: 1,
+            heading_text: heading_text.to_string(),
+            section_number: SectionNumber::parse(number).unwrap(),
+            line_number: 1,
+            source_file: PathBuf::from(format!("{number}.md")),
+            content: vec![],
+            metadata: None,
+        }
+    }
+
+    fn document(sections: Vec<MarkdownSection>) -> UnifiedDocument {
+        let mut doc = UnifiedDocument::new(test_metadata(), PathBuf::from("."));
+        doc.sections = sections;
+        doc
+    }
+
+    #[test]
+    fn test_build_toc_nests_deeply() {
+        let doc = document(vec![
+            section("1", "Introduction"),
+            section("1.1", "Purpose"),
+            section("1.1.1", "Scope"),
+            section("2", "Architecture"),
+        ]);
+
+        let toc = build_toc(&doc);
+        assert_eq!(toc.len(), 2);
+        assert_eq!(toc[0].title, "Introduction");
+        assert_eq!(toc[0].children.len(), 1);
+        assert_eq!(toc[0].children[0].title, "Purpose");
+        assert_eq!(toc[0].children[0].children.len(), 1);
+        assert_eq!(toc[0].children[0].children[0].title, "Scope");
+        assert_eq!(toc[1].title, "Architecture");
+        assert!(toc[1].children.is_empty());
+    }
+
+    #[test]
+    fn test_build_toc_handles_depth_gaps() {
+        // A "1" followed directly by a "1.1.1" (no intermediate "1.1")
+        // should still nest under "1".
+        let doc = document(vec![section("1", "Introduction"), section("1.1.1", "Deep")]);
+
+        let toc = build_toc(&doc);
+        assert_eq!(toc.len(), 1);
+        assert_eq!(toc[0].children.len(), 1);
+        assert_eq!(toc[0].children[0].title, "Deep");
+    }
+
+    #[test]
+    fn test_duplicate_headings_get_distinct_anchors() {
+        let doc = document(vec![
+            section("1", "Overview"),
+            section("2", "Overview"),
+            section("3", "Overview"),
+        ]);
+
+        let toc = build_toc(&doc);
+        let anchors: Vec<&str> = toc.iter().map(|e| e.anchor.as_str()).collect();
+        assert_eq!(anchors, vec!["overview", "overview-1", "overview-2"]);
+    }
+
+    #[test]
+    fn test_slugify_collapses_punctuation() {
+        assert_eq!(slugify("System Design & Architecture!"), "system-design-architecture");
+        assert_eq!(slugify(""), "section");
+    }
+
+    #[test]
+    fn test_build_flat_toc_preserves_order_without_nesting() {
+        let doc = document(vec![section("1", "Introduction"), section("1.1", "Purpose")]);
+
+        let flat = build_flat_toc(&doc);
+        assert_eq!(flat.len(), 2);
+        assert!(flat.iter().all(|e| e.children.is_empty()));
+        assert_eq!(flat[1].depth, 1);
+    }
+}
@@ -0,0 +1,43 @@
+//! Shared `#[cfg(test)]` fixtures for this crate's unit tests
+//!
+//! Every module that exercises [`UnifiedDocument`](crate::unified_document::UnifiedDocument)
+//! needs a populated [`DocumentMetadata`](crate::unified_document::DocumentMetadata), and that
+//! struct grows fields regularly (`syntax_theme`, `bibliography`, `citation_style`, ...). Keeping
+//! one fixture here means a new field is one edit instead of one per test module.
+
+#![cfg(test)]
+
+use crate::unified_document::{DocumentMetadata, Person};
+
+/// A fully populated `DocumentMetadata` fixture for unit tests
+pub fn test_metadata() -> DocumentMetadata {
+    DocumentMetadata {
+        system_id: None,
+        document_id: "TEST-001".to_string(),
+        title: "Test Document".to_string(),
+        subtitle: None,
+        description: None,
+        doc_type: "SDD".to_string(),
+        standard: "DI-IPSC-81435B".to_string(),
+        template: "sdd-standard-v1".to_string(),
+        owner: Person {
+            name: "John Doe".to_string(),
+            email: "john@example.com".to_string(),
+        },
+        approver: Person {
+            name: "Jane Smith".to_string(),
+            email: "jane@example.com".to_string(),
+        },
+        version: None,
+        modified: None,
+        revision_history: Vec::new(),
+        protection_mark: None,
+        title_page_background: None,
+        heading_color: "#2B579A".to_string(),
+        words_per_minute: 200,
+        count_image_alt_text: false,
+        syntax_theme: crate::model::DEFAULT_SYNTAX_THEME.to_string(),
+        bibliography: None,
+        citation_style: crate::citations::CitationStyle::Numeric,
+    }
+}
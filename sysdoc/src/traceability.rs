@@ -0,0 +1,320 @@
+//! Traceability validation over [`SectionMetadata`](crate::source_model::SectionMetadata)
+//!
+//! Builds a requirements-traceability graph from the `section_id`/`traced_ids`
+//! declared on each [`MarkdownSection`](crate::source_model::MarkdownSection)
+//! and validates it for common authoring mistakes: duplicate ids, dangling
+//! traces, uncovered (orphan) sections, and trace cycles.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use crate::source_model::MarkdownSection;
+use crate::unified_document::UnifiedDocument;
+
+/// A single traceability problem found in the document
+#[derive(Debug, Clone, PartialEq)]
+pub enum Finding {
+    /// The same `section_id` is declared on more than one section
+    DuplicateSectionId {
+        section_id: String,
+        source_file: PathBuf,
+        line_number: usize,
+    },
+    /// A `traced_id` does not match any `section_id` present in the document
+    DanglingTrace {
+        traced_id: String,
+        source_file: PathBuf,
+        line_number: usize,
+    },
+    /// A declared `section_id` is never referenced by anyone's `traced_ids`
+    OrphanSection {
+        section_id: String,
+        source_file: PathBuf,
+        line_number: usize,
+    },
+    /// A cycle was found in the trace graph (e.g. REQ -> SRS -> REQ)
+    Cycle { section_ids: Vec<String> },
+}
+
+/// Result of validating a document's traceability graph
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    /// Problems found, in detection order: duplicates, dangling traces, orphans, cycles
+    pub findings: Vec<Finding>,
+    /// Fraction (0.0-1.0) of declared `section_id`s referenced by at least one `traced_ids`
+    pub coverage: f64,
+}
+
+impl Report {
+    /// Whether the document's traceability graph has no findings
+    pub fn is_clean(&self) -> bool {
+        self.findings.is_empty()
+    }
+}
+
+/// Validate the traceability graph of a document
+///
+/// # Parameters
+/// * `document` - The unified document whose sections carry traceability metadata
+///
+/// # Returns
+/// * `Report` - Findings plus the overall trace coverage fraction (0.0-1.0)
+pub fn validate(document: &UnifiedDocument) -> Report {
+    let mut findings = Vec::new();
+
+    let section_index = build_section_index(&document.sections);
+    for (section_id, sections) in &section_index {
+        for section in sections.iter().skip(1) {
+            findings.push(Finding::DuplicateSectionId {
+                section_id: section_id.clone(),
+                source_file: section.source_file.clone(),
+                line_number: section.line_number,
+            });
+        }
+    }
+
+    let mut traced_by: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut edges: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for section in &document.sections {
+        let Some(metadata) = &section.metadata else {
+            continue;
+        };
+        let Some(from_id) = metadata.section_id.as_deref() else {
+            continue;
+        };
+        let Some(traced_ids) = &metadata.traced_ids else {
+            continue;
+        };
+
+        edges
+            .entry(from_id.to_string())
+            .or_default()
+            .extend(traced_ids.iter().cloned());
+
+        for traced_id in traced_ids {
+            traced_by
+                .entry(traced_id.clone())
+                .or_default()
+                .push(from_id.to_string());
+
+            if !section_index.contains_key(traced_id) {
+                findings.push(Finding::DanglingTrace {
+                    traced_id: traced_id.clone(),
+                    source_file: section.source_file.clone(),
+                    line_number: section.line_number,
+                });
+            }
+        }
+    }
+
+    for (section_id, sections) in &section_index {
+        if !traced_by.contains_key(section_id) {
+            let section = sections[0];
+            findings.push(Finding::OrphanSection {
+                section_id: section_id.clone(),
+                source_file: section.source_file.clone(),
+                line_number: section.line_number,
+            });
+        }
+    }
+
+    findings.extend(find_cycles(&edges).into_iter().map(|section_ids| Finding::Cycle { section_ids }));
+
+    let declared = section_index.len();
+    let covered = section_index
+        .keys()
+        .filter(|id| traced_by.contains_key(*id))
+        .count();
+    let coverage = if declared == 0 {
+        1.0
+    } else {
+        covered as f64 / declared as f64
+    };
+
+    Report { findings, coverage }
+}
+
+/// Index sections by their declared `section_id`, preserving declaration order
+/// for each id so the first entry is treated as authoritative and later ones
+/// are reported as duplicates.
+fn build_section_index(sections: &[MarkdownSection]) -> BTreeMap<String, Vec<&MarkdownSection>> {
+    let mut index: BTreeMap<String, Vec<&MarkdownSection>> = BTreeMap::new();
+    for section in sections {
+        if let Some(metadata) = &section.metadata {
+            if let Some(section_id) = &metadata.section_id {
+                index.entry(section_id.clone()).or_default().push(section);
+            }
+        }
+    }
+    index
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VisitState {
+    Visiting,
+    Done,
+}
+
+/// Find cycles in the trace edge graph via DFS, returning each distinct cycle
+/// (deduplicated regardless of which node it was discovered from) as the
+/// ordered list of `section_id`s that form it.
+fn find_cycles(edges: &BTreeMap<String, Vec<String>>) -> Vec<Vec<String>> {
+    let mut state: BTreeMap<&str, VisitState> = BTreeMap::new();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut cycles = Vec::new();
+    let mut seen_cycles: BTreeSet<Vec<String>> = BTreeSet::new();
+
+    for node in edges.keys() {
+        visit_for_cycles(node, edges, &mut state, &mut stack, &mut cycles, &mut seen_cycles);
+    }
+
+    cycles
+}
+
+fn visit_for_cycles<'a>(
+    node: &'a str,
+    edges: &'a BTreeMap<String, Vec<String>>,
+    state: &mut BTreeMap<&'a str, VisitState>,
+    stack: &mut Vec<&'a str>,
+    cycles: &mut Vec<Vec<String>>,
+    seen_cycles: &mut BTreeSet<Vec<String>>,
+) {
+    if state.get(node) == Some(&VisitState::Done) {
+        return;
+    }
+    if let Some(start) = stack.iter().position(|n| *n == node) {
+        let mut cycle: Vec<String> = stack[start..].iter().map(|s| s.to_string()).collect();
+        cycle.push(node.to_string());
+        let mut key = cycle.clone();
+        key.sort();
+        if seen_cycles.insert(key) {
+            cycles.push(cycle);
+        }
+        return;
+    }
+
+    stack.push(node);
+    state.insert(node, VisitState::Visiting);
+    if let Some(neighbors) = edges.get(node) {
+        for next in neighbors {
+            visit_for_cycles(next.as_str(), edges, state, stack, cycles, seen_cycles);
+        }
+    }
+    stack.pop();
+    state.insert(node, VisitState::Done);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source_model::SectionNumber;
+    use crate::test_support::test_metadata;
+
+    fn section(
+        section_id: Option<&str>,
+        traced_ids: Option<Vec<&str>>,
+        source_file: &str,
+        line_number: usize,
+    ) -> MarkdownSection {
+        use crate::source_model::SectionMetadata;
+
+        MarkdownSection {
+            heading_level: 1,
+            heading_text: section_id.unwrap_or("Untitled").to_string(),
+            section_number: SectionNumber::parse("1").unwrap(),
+            line_number,
+            source_file: PathBuf::from(source_file),
+            content: vec![],
+            metadata: Some(SectionMetadata {
+                section_id: section_id.map(|s| s.to_string()),
+                traced_ids: traced_ids.map(|ids| ids.into_iter().map(|s| s.to_string()).collect()),
+                generate_section_id_to_traced_ids_table: false,
+                generate_traced_ids_to_section_ids_table: false,
+            }),
+        }
+    }
+
+    fn document(sections: Vec<MarkdownSection>) -> UnifiedDocument {
+        let mut doc = UnifiedDocument::new(test_metadata(), PathBuf::from("."));
+        doc.sections = sections;
+        doc
+    }
+
+    #[test]
+    fn test_chain_reports_only_untraced_head_as_orphan() {
+        // SRS-001 traces REQ-001, but nothing traces SRS-001, so the head of
+        // the chain is the only finding and half the ids are covered.
+        let doc = document(vec![
+            section(Some("SRS-001"), Some(vec!["REQ-001"]), "srs.md", 1),
+            section(Some("REQ-001"), None, "req.md", 1),
+        ]);
+
+        let report = validate(&doc);
+        assert!(matches!(
+            report.findings.as_slice(),
+            [Finding::OrphanSection { section_id, .. }] if section_id == "SRS-001"
+        ));
+        assert_eq!(report.coverage, 0.5);
+    }
+
+    #[test]
+    fn test_duplicate_section_id_detected() {
+        let doc = document(vec![
+            section(Some("REQ-001"), None, "a.md", 1),
+            section(Some("REQ-001"), None, "b.md", 5),
+        ]);
+
+        let report = validate(&doc);
+        assert!(matches!(
+            report.findings.as_slice(),
+            [
+                Finding::DuplicateSectionId { section_id, source_file, line_number },
+                Finding::OrphanSection { section_id: orphan_id, .. },
+            ] if section_id == "REQ-001"
+                && source_file == &PathBuf::from("b.md")
+                && *line_number == 5
+                && orphan_id == "REQ-001"
+        ));
+    }
+
+    #[test]
+    fn test_dangling_trace_detected() {
+        let doc = document(vec![section(
+            Some("SRS-001"),
+            Some(vec!["REQ-999"]),
+            "srs.md",
+            3,
+        )]);
+
+        let report = validate(&doc);
+        assert!(report
+            .findings
+            .iter()
+            .any(|f| matches!(f, Finding::DanglingTrace { traced_id, .. } if traced_id == "REQ-999")));
+    }
+
+    #[test]
+    fn test_orphan_section_detected() {
+        let doc = document(vec![section(Some("REQ-001"), None, "req.md", 1)]);
+
+        let report = validate(&doc);
+        assert!(report
+            .findings
+            .iter()
+            .any(|f| matches!(f, Finding::OrphanSection { section_id, .. } if section_id == "REQ-001")));
+        assert_eq!(report.coverage, 0.0);
+    }
+
+    #[test]
+    fn test_cycle_detected() {
+        let doc = document(vec![
+            section(Some("REQ-001"), Some(vec!["SRS-001"]), "req.md", 1),
+            section(Some("SRS-001"), Some(vec!["REQ-001"]), "srs.md", 1),
+        ]);
+
+        let report = validate(&doc);
+        assert!(report.findings.iter().any(|f| matches!(f, Finding::Cycle { .. })));
+    }
+}
@@ -55,11 +55,28 @@ impl UnifiedDocument {
 
     /// Get the total word count across all sections
     ///
+    /// Walks each section's text-bearing content blocks (paragraphs, list
+    /// items, table cells, headings), splitting on Unicode whitespace. Code
+    /// blocks are excluded; image alt text is excluded unless requested.
+    ///
     /// # Returns
-    /// * `usize` - Total word count (currently counts content blocks, not actual words)
+    /// * `usize` - Total word count across all sections
     pub fn word_count(&self) -> usize {
-        // TODO: Implement proper word counting from MarkdownBlock content
-        self.sections.iter().map(|s| s.content.len()).sum()
+        self.sections
+            .iter()
+            .map(|s| s.word_count(self.metadata.count_image_alt_text))
+            .sum()
+    }
+
+    /// Estimate the reading time for the whole document
+    ///
+    /// Computed as `ceil(word_count / words_per_minute)` using the rate from
+    /// [`DocumentMetadata::words_per_minute`].
+    ///
+    /// # Returns
+    /// * `u32` - Estimated reading time in minutes (minimum 1 if there are any words)
+    pub fn reading_time_minutes(&self) -> u32 {
+        reading_time_minutes(self.word_count(), self.metadata.words_per_minute)
     }
 
     /// Get the total number of images
@@ -111,6 +128,16 @@ pub struct DocumentMetadata {
     pub title_page_background: Option<String>,
     /// Heading color for PDF output as a hex color string (e.g., "#2B579A")
     pub heading_color: String,
+    /// Reading speed used for reading-time estimates, in words per minute (default 200)
+    pub words_per_minute: u32,
+    /// Whether image alt text counts towards word count / reading time
+    pub count_image_alt_text: bool,
+    /// Name of the bundled syntect theme used to highlight fenced code blocks
+    pub syntax_theme: String,
+    /// Optional path to a `.bib` bibliography file for inline citations
+    pub bibliography: Option<PathBuf>,
+    /// How inline citations and the References section are rendered
+    pub citation_style: crate::citations::CitationStyle,
 }
 
 /// Person information
@@ -131,6 +158,22 @@ pub struct RevisionHistoryEntry {
     pub description: String,
 }
 
+/// Compute a reading time estimate in whole minutes
+///
+/// # Parameters
+/// * `words` - Number of words to read
+/// * `words_per_minute` - Reading speed in words per minute
+///
+/// # Returns
+/// * `u32` - `ceil(words / words_per_minute)`, minimum 1 if `words > 0`
+pub(crate) fn reading_time_minutes(words: usize, words_per_minute: u32) -> u32 {
+    if words == 0 {
+        return 0;
+    }
+    let wpm = words_per_minute.max(1) as usize;
+    (words.div_ceil(wpm)) as u32
+}
+
 /// Format an ISO 8601 date string to display format (e.g., "6 Jul 2026")
 ///
 /// # Parameters
@@ -223,34 +266,8 @@ impl DocumentBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::source_model::SectionNumber;
-
-    fn test_metadata() -> DocumentMetadata {
-        DocumentMetadata {
-            system_id: None,
-            document_id: "TEST-001".to_string(),
-            title: "Test Document".to_string(),
-            subtitle: None,
-            description: None,
-            doc_type: "SDD".to_string(),
-            standard: "DI-IPSC-81435B".to_string(),
-            template: "sdd-standard-v1".to_string(),
-            owner: Person {
-                name: "John Doe".to_string(),
-                email: "john@example.com".to_string(),
-            },
-            approver: Person {
-                name: "Jane Smith".to_string(),
-                email: "jane@example.com".to_string(),
-            },
-            version: None,
-            modified: None,
-            revision_history: Vec::new(),
-            protection_mark: None,
-            title_page_background: None,
-            heading_color: "#2B579A".to_string(),
-        }
-    }
+    use crate::source_model::{MarkdownBlock, SectionNumber};
+    use crate::test_support::test_metadata;
 
     #[test]
     fn test_document_builder() {
@@ -309,4 +326,66 @@ mod tests {
         assert_eq!(format_display_date("2024-13-01"), "2024-13-01"); // Invalid month
         assert_eq!(format_display_date(""), "");
     }
+
+    fn section_with(number: &str, blocks: Vec<MarkdownBlock>) -> MarkdownSection {
+        MarkdownSection {
+            heading_level: 1,
+            heading_text: format!("Section {number}"),
+            section_number: SectionNumber::parse(number).unwrap(),
+            line_number: 1,
+            source_file: PathBuf::from(format!("{number}.md")),
+            content: blocks,
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn test_word_count_aggregates_across_sections() {
+        let mut doc = UnifiedDocument::new(test_metadata(), PathBuf::from("."));
+        doc.sections.push(section_with(
+            "1",
+            vec![MarkdownBlock::Paragraph {
+                text: "three word paragraph".to_string(),
+            }],
+        ));
+        doc.sections.push(section_with(
+            "2",
+            vec![
+                MarkdownBlock::Heading {
+                    level: 2,
+                    text: "two words".to_string(),
+                },
+                MarkdownBlock::Code {
+                    lang: Some("rust".to_string()),
+                    highlighted_html: "<span>fn f() {}</span>".to_string(),
+                    raw: "fn f() {}".to_string(),
+                },
+            ],
+        ));
+
+        assert_eq!(doc.word_count(), 5);
+    }
+
+    #[test]
+    fn test_reading_time_rounds_up() {
+        let mut metadata = test_metadata();
+        metadata.words_per_minute = 200;
+        let mut doc = UnifiedDocument::new(metadata, PathBuf::from("."));
+        let words: Vec<&str> = vec!["word"; 201];
+        doc.sections.push(section_with(
+            "1",
+            vec![MarkdownBlock::Paragraph {
+                text: words.join(" "),
+            }],
+        ));
+
+        assert_eq!(doc.word_count(), 201);
+        assert_eq!(doc.reading_time_minutes(), 2);
+    }
+
+    #[test]
+    fn test_reading_time_zero_words() {
+        let doc = UnifiedDocument::new(test_metadata(), PathBuf::from("."));
+        assert_eq!(doc.reading_time_minutes(), 0);
+    }
 }
@@ -0,0 +1,238 @@
+//! Watch mode: incremental rebuild on source file changes
+//!
+//! Watches a document's source `root` tree for markdown, CSV, and
+//! image/SVG changes and rebuilds only the sections/tables sourced from the
+//! files that changed, instead of reparsing the whole tree on every edit.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::time::Duration;
+
+use notify::{RecursiveMode, Watcher as _};
+
+use crate::source_model::{MarkdownSection, TableSource};
+use crate::unified_document::{DocumentBuilder, UnifiedDocument};
+
+/// How long to wait after the last filesystem event before rebuilding, so a
+/// burst of saves (e.g. a find-and-replace across files) triggers one rebuild
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// File extensions that trigger a rebuild when added, modified, or removed
+const WATCHED_EXTENSIONS: &[&str] = &["md", "csv", "svg", "png", "jpg", "jpeg"];
+
+/// Re-parses individual source files on demand.
+///
+/// Kept as a trait so this module stays decoupled from the Stage 1 parsing
+/// pipeline: the caller supplies whatever loader already turns a file on
+/// disk into [`MarkdownSection`]s and [`TableSource`]s.
+pub trait SourceLoader {
+    /// Parse `path` into the sections it contributes, or an empty `Vec` if
+    /// the file no longer exists or isn't a markdown source
+    fn load_sections(&self, path: &Path) -> Vec<MarkdownSection>;
+
+    /// Parse `path` into the table it contributes, or `None` if it isn't a
+    /// CSV table source (or no longer exists)
+    fn load_table(&self, path: &Path) -> Option<TableSource>;
+}
+
+/// Run a long-lived watch loop over `document.root`, rebuilding `document` in
+/// place after each debounced batch of changes and invoking `on_rebuild`.
+///
+/// # Parameters
+/// * `document` - The document to keep up to date
+/// * `loader` - Reparses individual files when they change
+/// * `on_rebuild` - Called with the rebuilt document after each batch of changes
+///
+/// # Returns
+/// * `notify::Result<()>` - `Err` if the watcher can't be created or started;
+///   `Ok(())` once the event channel closes. Error events received while
+///   watching are ignored.
+pub fn watch(
+    document: &mut UnifiedDocument,
+    loader: &dyn SourceLoader,
+    mut on_rebuild: impl FnMut(&UnifiedDocument),
+) -> notify::Result<()> {
+    let (tx, rx) = channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    watcher.watch(&document.root, RecursiveMode::Recursive)?;
+
+    loop {
+        let Ok(first_event) = rx.recv() else {
+            return Ok(());
+        };
+
+        let mut changed_paths = HashSet::new();
+        collect_changed_paths(first_event, &mut changed_paths);
+
+        loop {
+            match rx.recv_timeout(DEBOUNCE) {
+                Ok(event) => collect_changed_paths(event, &mut changed_paths),
+                Err(RecvTimeoutError::Timeout) | Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+
+        if changed_paths.is_empty() {
+            continue;
+        }
+
+        rebuild_changed(document, loader, &changed_paths);
+        on_rebuild(document);
+    }
+}
+
+fn collect_changed_paths(event: notify::Result<notify::Event>, changed: &mut HashSet<PathBuf>) {
+    let Ok(event) = event else { return };
+    for path in event.paths {
+        if is_watched(&path) {
+            changed.insert(path);
+        }
+    }
+}
+
+fn is_watched(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| WATCHED_EXTENSIONS.iter().any(|w| w.eq_ignore_ascii_case(ext)))
+}
+
+/// Rebuild only the sections/tables sourced from `changed_paths`, keeping
+/// everything else untouched, then re-sort by `SectionNumber` so deletions,
+/// renames, and renumbering of other files stay consistent.
+fn rebuild_changed(
+    document: &mut UnifiedDocument,
+    loader: &dyn SourceLoader,
+    changed_paths: &HashSet<PathBuf>,
+) {
+    let mut builder = DocumentBuilder::new(document.metadata.clone(), document.root.clone());
+
+    for section in document.sections.drain(..) {
+        if !changed_paths.contains(&section.source_file) {
+            builder.add_section(section);
+        }
+    }
+    for table in document.tables.drain(..) {
+        if !changed_paths.contains(&table.path) {
+            builder.add_table(table);
+        }
+    }
+
+    for path in changed_paths {
+        for section in loader.load_sections(path) {
+            builder.add_section(section);
+        }
+        if let Some(table) = loader.load_table(path) {
+            builder.add_table(table);
+        }
+    }
+
+    let mut rebuilt = builder.build();
+    rebuilt
+        .sections
+        .sort_by(|a, b| a.section_number.cmp(&b.section_number));
+    *document = rebuilt;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source_model::SectionNumber;
+    use crate::test_support::test_metadata;
+
+    fn section(number: &str, source_file: &str) -> MarkdownSection {
+        MarkdownSection {
+            heading_level: 1,
+            heading_text: format!("Section {number}"),
+            section_number: SectionNumber::parse(number).unwrap(),
+            line_number: 1,
+            source_file: PathBuf::from(source_file),
+            content: vec![],
+            metadata: None,
+        }
+    }
+
+    /// A loader that serves a fixed, in-memory set of sections keyed by path,
+    /// standing in for the real Stage 1 file parser in tests.
+    struct FakeLoader {
+        sections_by_path: std::collections::HashMap<PathBuf, Vec<MarkdownSection>>,
+    }
+
+    impl SourceLoader for FakeLoader {
+        fn load_sections(&self, path: &Path) -> Vec<MarkdownSection> {
+            self.sections_by_path.get(path).cloned().unwrap_or_default()
+        }
+
+        fn load_table(&self, _path: &Path) -> Option<TableSource> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_rebuild_changed_only_reparses_changed_file() {
+        let mut document = UnifiedDocument::new(test_metadata(), PathBuf::from("."));
+        document.sections.push(section("1", "01_intro.md"));
+        document.sections.push(section("2", "02_design.md"));
+
+        let mut sections_by_path = std::collections::HashMap::new();
+        sections_by_path.insert(
+            PathBuf::from("02_design.md"),
+            vec![section("2", "02_design.md")],
+        );
+        let loader = FakeLoader { sections_by_path };
+
+        let changed: HashSet<PathBuf> = [PathBuf::from("02_design.md")].into_iter().collect();
+        rebuild_changed(&mut document, &loader, &changed);
+
+        assert_eq!(document.sections.len(), 2);
+        assert!(document
+            .sections
+            .iter()
+            .any(|s| s.source_file == PathBuf::from("01_intro.md")));
+    }
+
+    #[test]
+    fn test_rebuild_changed_drops_deleted_file_sections() {
+        let mut document = UnifiedDocument::new(test_metadata(), PathBuf::from("."));
+        document.sections.push(section("1", "01_intro.md"));
+        document.sections.push(section("2", "02_design.md"));
+
+        // The loader reports no sections for the deleted file
+        let loader = FakeLoader {
+            sections_by_path: std::collections::HashMap::new(),
+        };
+
+        let changed: HashSet<PathBuf> = [PathBuf::from("02_design.md")].into_iter().collect();
+        rebuild_changed(&mut document, &loader, &changed);
+
+        assert_eq!(document.sections.len(), 1);
+        assert_eq!(document.sections[0].source_file, PathBuf::from("01_intro.md"));
+    }
+
+    #[test]
+    fn test_rebuild_changed_resorts_by_section_number() {
+        let mut document = UnifiedDocument::new(test_metadata(), PathBuf::from("."));
+        document.sections.push(section("2", "02_design.md"));
+
+        let mut sections_by_path = std::collections::HashMap::new();
+        sections_by_path.insert(
+            PathBuf::from("01_intro.md"),
+            vec![section("1", "01_intro.md")],
+        );
+        let loader = FakeLoader { sections_by_path };
+
+        let changed: HashSet<PathBuf> = [PathBuf::from("01_intro.md")].into_iter().collect();
+        rebuild_changed(&mut document, &loader, &changed);
+
+        assert_eq!(document.sections[0].source_file, PathBuf::from("01_intro.md"));
+        assert_eq!(document.sections[1].source_file, PathBuf::from("02_design.md"));
+    }
+
+    #[test]
+    fn test_is_watched_extensions() {
+        assert!(is_watched(Path::new("01_intro.md")));
+        assert!(is_watched(Path::new("data.CSV")));
+        assert!(is_watched(Path::new("diagram.drawio.svg")));
+        assert!(!is_watched(Path::new("notes.txt")));
+        assert!(!is_watched(Path::new("no_extension")));
+    }
+}